@@ -1,17 +1,616 @@
+use bevy::input::mouse::MouseMotion;
 use bevy::prelude::*;
+// `bevy::prelude::Camera` is the render component; alias it since `nokhwa::Camera` (the
+// webcam handle, imported below) also needs the bare name `Camera`.
+use bevy::render::camera::Camera as RenderCamera;
+use bevy::winit::{UpdateMode, WinitSettings};
+use ndarray::{s, Array4, ArrayView1, ArrayView2, Axis};
 use nokhwa::pixel_format::RgbFormat;
 use nokhwa::utils::{CameraIndex, RequestedFormat, RequestedFormatType};
 use nokhwa::Camera;
 use rustface::ImageData;
+use sha2::{Digest, Sha256};
+use std::io::{Read, Write};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum EyeSide {
+    Left,
+    Right,
+}
+
 #[derive(Component)]
-struct Eye;
+struct Eye {
+    side: EyeSide,
+    target: Option<TrackedFace>, // face this eye is currently converging on, if any
+    /// World-space center this eye's pair is built around, so multiple tracked faces get
+    /// visually separate eye pairs instead of every pair sitting stacked at the origin.
+    base: Vec3,
+}
+
+#[derive(Resource)]
+struct EyeScene(Handle<Scene>);
+
+/// One eye pair (left + right) tracking a single subject. Kept in a resource, rather than
+/// derived purely from a query each frame, so the left and right eye of a pair always agree
+/// on which face they're converging on instead of being matched independently.
+struct EyePair {
+    left: Entity,
+    right: Entity,
+    last_position: (f32, f32),
+    /// The default pair created in `setup` is never despawned, so the eyes have somewhere to
+    /// rest (centered) instead of disappearing when nobody is being tracked.
+    persistent: bool,
+}
+
+#[derive(Resource, Default)]
+struct EyePairs {
+    pairs: Vec<EyePair>,
+}
+
+/// Tunable calibration for the depth estimate and eye geometry used to drive vergence.
+#[derive(Resource, Clone, Copy)]
+struct EyeVergenceSettings {
+    /// `distance ≈ distance_calibration / bbox_height`, where `bbox_height` is the detected
+    /// face height as a fraction of the frame height. Bigger faces (closer subjects) yield a
+    /// smaller estimated distance.
+    distance_calibration: f32,
+    /// World-space separation between the left and right eye.
+    inter_pupil_distance: f32,
+}
+
+impl Default for EyeVergenceSettings {
+    fn default() -> Self {
+        Self {
+            distance_calibration: 1.2,
+            inter_pupil_distance: 0.15,
+        }
+    }
+}
+
+/// A tracked face's screen position and apparent size, used to estimate how far away it is.
+#[derive(Debug, Clone, Copy)]
+struct TrackedFace {
+    position: (f32, f32), // Normalized center (-1 to 1)
+    bbox_height: f32,     // Detected face height as a fraction of the frame height
+}
 
 #[derive(Resource, Clone)]
 struct FacePosition {
-    position: Arc<Mutex<Option<(f32, f32)>>>, // Normalized position (-1 to 1)
+    faces: Arc<Mutex<Vec<TrackedFace>>>, // All currently tracked faces
+}
+
+/// How long to keep rendering continuously after the last face sighting before dropping back
+/// to low-power reactive rendering.
+const FACE_IDLE_TIMEOUT_SECS: f64 = 2.0;
+
+/// Marks the user-controlled fly camera (WASD + mouse-look), as distinct from any cameras
+/// baked into the loaded glTF scene.
+#[derive(Component)]
+struct FlyCamera;
+
+/// Current yaw/pitch of the fly camera, tracked explicitly so mouse-look can accumulate
+/// smoothly without drifting into unwanted roll.
+#[derive(Resource, Default)]
+struct FlyCameraState {
+    yaw: f32,
+    pitch: f32,
+}
+
+/// Every camera the user can cycle through with `C`: the fly camera plus any cameras defined
+/// inside `eye-model.gltf`, in the order they were spawned. Switching sets the active index's
+/// camera to active and disables the rest.
+#[derive(Resource, Default)]
+struct CameraCycle {
+    cameras: Vec<Entity>,
+    active_index: usize,
+}
+
+/// Tracks when a face was last seen so `update_render_mode` can decide between continuous
+/// rendering (smooth slerp while a face moves) and reactive rendering (idle, no face around).
+#[derive(Resource, Default)]
+struct FaceTrackingActivity {
+    last_face_seen: Option<f64>, // App uptime, in seconds, at the last sighting
+    reactive: bool,              // Whether WinitSettings is currently in low-power reactive mode
+}
+
+/// A single face (or other object) detection before non-maximum suppression.
+#[derive(Debug, Clone, Copy)]
+struct FaceBox {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    score: f32,
+}
+
+/// Intersection-over-union of two detections, treating degenerate (zero-area) boxes as 0.
+fn intersection_over_union(a: &FaceBox, b: &FaceBox) -> f32 {
+    let a_right = a.x + a.width as i32;
+    let a_bottom = a.y + a.height as i32;
+    let b_right = b.x + b.width as i32;
+    let b_bottom = b.y + b.height as i32;
+
+    let inter_w = (a_right.min(b_right) - a.x.max(b.x)).max(0) as f32;
+    let inter_h = (a_bottom.min(b_bottom) - a.y.max(b.y)).max(0) as f32;
+    let intersection = inter_w * inter_h;
+
+    let area_a = (a.width * a.height) as f32;
+    let area_b = (b.width * b.height) as f32;
+    let union = area_a + area_b - intersection;
+
+    if union <= 0.0 {
+        0.0
+    } else {
+        intersection / union
+    }
+}
+
+const NMS_IOU_THRESHOLD: f32 = 0.3;
+
+/// Greedily keep the highest-scoring detections, dropping any later box that overlaps a kept
+/// one by more than `iou_threshold`. This collapses the duplicate/overlapping boxes a detector
+/// tends to emit per face down to one box per face.
+fn non_max_suppression(mut detections: Vec<FaceBox>, iou_threshold: f32) -> Vec<FaceBox> {
+    detections.retain(|d| d.width > 0 && d.height > 0);
+    detections.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut kept: Vec<FaceBox> = Vec::new();
+    for candidate in detections {
+        let overlaps_kept = kept
+            .iter()
+            .any(|k| intersection_over_union(k, &candidate) > iou_threshold);
+        if !overlaps_kept {
+            kept.push(candidate);
+        }
+    }
+    kept
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+/// Produces candidate face boxes from a frame, letting `run_face_detection` swap detection
+/// backends without touching the capture/NMS pipeline. Both a grayscale plane and the raw RGB
+/// frame are passed in since backends disagree on what they want: the SeetaFace cascade only
+/// ever looks at `gray`, while BlazeFace expects RGB. Implementations may still return
+/// overlapping boxes per face; the caller runs them through `non_max_suppression`.
+trait FaceDetector: Send + Sync {
+    fn detect(&self, rgb: &[u8], gray: &ImageData, width: u32, height: u32) -> Vec<FaceBox>;
+}
+
+/// Wraps the rustface/SeetaFace cascade: CPU-only, grayscale, well-tuned for frontal faces.
+/// rustface's detector needs `&mut self` internally, so it's kept behind a mutex to satisfy
+/// the shared `FaceDetector` interface.
+struct SeetaFaceDetector {
+    detector: Mutex<Box<dyn rustface::Detector>>,
+}
+
+impl SeetaFaceDetector {
+    fn new(model_path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut detector = rustface::create_detector(&model_path.to_string_lossy())?;
+        detector.set_min_face_size(30);
+        detector.set_score_thresh(1.0);
+        detector.set_pyramid_scale_factor(0.8);
+        detector.set_slide_window_step(4, 4);
+
+        Ok(Self {
+            detector: Mutex::new(detector),
+        })
+    }
+}
+
+impl FaceDetector for SeetaFaceDetector {
+    fn detect(&self, _rgb: &[u8], gray: &ImageData, _width: u32, _height: u32) -> Vec<FaceBox> {
+        let Ok(mut detector) = self.detector.lock() else {
+            return Vec::new();
+        };
+
+        detector
+            .detect(gray)
+            .iter()
+            .map(|face| {
+                let bbox = face.bbox();
+                FaceBox {
+                    x: bbox.x(),
+                    y: bbox.y(),
+                    width: bbox.width(),
+                    height: bbox.height(),
+                    score: face.score() as f32,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Runs a BlazeFace-style ONNX model: many candidate boxes and confidence scores per frame,
+/// trading the SeetaFace cascade's CPU-only grayscale path for a faster model that tends to
+/// do better on small or front-facing faces (the same "front model vs back model" tradeoff
+/// selfie-oriented face pipelines make).
+struct BlazeFaceDetector {
+    session: Mutex<ort::Session>,
+    anchors: Vec<Anchor>,
+    score_threshold: f32,
+}
+
+impl BlazeFaceDetector {
+    const INPUT_SIZE: u32 = 128;
+
+    fn new(model_path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let environment = ort::Environment::builder()
+            .with_name("blazeface")
+            .build()?
+            .into_arc();
+        let session = ort::SessionBuilder::new(&environment)?.with_model_from_file(model_path)?;
+
+        Ok(Self {
+            session: Mutex::new(session),
+            anchors: generate_anchors(),
+            score_threshold: 0.5,
+        })
+    }
+
+    /// Resizes the RGB frame to the model's input size, normalizes each channel to [-1, 1], and
+    /// lays it out as an NCHW tensor (BlazeFace expects 3-channel RGB, not a grayscale plane).
+    fn prepare_input(&self, rgb: &[u8], width: u32, height: u32) -> Array4<f32> {
+        let size = Self::INPUT_SIZE as usize;
+        let mut input = Array4::<f32>::zeros((1, 3, size, size));
+        for y in 0..size {
+            for x in 0..size {
+                let src_x = (x as u32 * width / Self::INPUT_SIZE).min(width - 1) as usize;
+                let src_y = (y as u32 * height / Self::INPUT_SIZE).min(height - 1) as usize;
+                let idx = (src_y * width as usize + src_x) * 3;
+                for channel in 0..3 {
+                    let pixel = rgb[idx + channel] as f32;
+                    input[[0, channel, y, x]] = pixel / 127.5 - 1.0;
+                }
+            }
+        }
+        input
+    }
+
+    /// Decodes raw per-anchor box regressions and scores into frame-space `FaceBox`es. BlazeFace
+    /// emits offsets relative to a fixed SSD anchor grid (see `generate_anchors`) rather than
+    /// ready-made boxes, so each candidate has to be un-normalized against its anchor and its
+    /// score passed through a sigmoid before either means anything.
+    fn decode_candidates(
+        &self,
+        raw_boxes: ArrayView2<f32>,
+        raw_scores: ArrayView1<f32>,
+        width: u32,
+        height: u32,
+    ) -> Vec<FaceBox> {
+        let input_size = Self::INPUT_SIZE as f32;
+        raw_boxes
+            .outer_iter()
+            .zip(raw_scores.iter())
+            .zip(self.anchors.iter())
+            .filter_map(|((raw_box, &raw_score), anchor)| {
+                let score = sigmoid(raw_score);
+                if score < self.score_threshold {
+                    return None;
+                }
+
+                let cx = raw_box[0] / input_size * anchor.w + anchor.x_center;
+                let cy = raw_box[1] / input_size * anchor.h + anchor.y_center;
+                let bw = raw_box[2] / input_size * anchor.w;
+                let bh = raw_box[3] / input_size * anchor.h;
+
+                Some(FaceBox {
+                    x: ((cx - bw / 2.0) * width as f32) as i32,
+                    y: ((cy - bh / 2.0) * height as f32) as i32,
+                    width: (bw * width as f32) as u32,
+                    height: (bh * height as f32) as u32,
+                    score,
+                })
+            })
+            .collect()
+    }
+}
+
+impl FaceDetector for BlazeFaceDetector {
+    fn detect(&self, rgb: &[u8], _gray: &ImageData, width: u32, height: u32) -> Vec<FaceBox> {
+        let Ok(session) = self.session.lock() else {
+            return Vec::new();
+        };
+
+        let input = self.prepare_input(rgb, width, height);
+        let input_value = match ort::Value::from_array(session.allocator(), &input) {
+            Ok(value) => value,
+            Err(e) => {
+                eprintln!("BlazeFace input error: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let outputs = match session.run(ort::inputs![input_value]) {
+            Ok(outputs) => outputs,
+            Err(e) => {
+                eprintln!("BlazeFace inference error: {}", e);
+                return Vec::new();
+            }
+        };
+
+        // The short-range model emits two tensors: per-anchor box regressions shaped
+        // [1, 896, 16] (4 box offsets + 12 keypoint coordinates we don't use) and per-anchor
+        // scores shaped [1, 896, 1].
+        let raw_boxes: ort::tensor::OrtOwnedTensor<f32, _> = match outputs[0].try_extract() {
+            Ok(tensor) => tensor,
+            Err(e) => {
+                eprintln!("BlazeFace box output error: {}", e);
+                return Vec::new();
+            }
+        };
+        let raw_scores: ort::tensor::OrtOwnedTensor<f32, _> = match outputs[1].try_extract() {
+            Ok(tensor) => tensor,
+            Err(e) => {
+                eprintln!("BlazeFace score output error: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let boxes = raw_boxes.view().index_axis(Axis(0), 0).slice(s![.., 0..4]).to_owned();
+        let scores = raw_scores.view().index_axis(Axis(0), 0).index_axis(Axis(1), 0).to_owned();
+
+        self.decode_candidates(boxes.view(), scores.view(), width, height)
+    }
+}
+
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// One SSD prior box in normalized (0..1) image coordinates, anchored to a feature-map cell.
+/// BlazeFace uses `fixed_anchor_size`, so every anchor's `w`/`h` is exactly 1.0 (the full image)
+/// and only `x_center`/`y_center` vary; box regressions are then scaled against these.
+struct Anchor {
+    x_center: f32,
+    y_center: f32,
+    w: f32,
+    h: f32,
+}
+
+/// Regenerates the fixed SSD anchor grid the short-range BlazeFace model was trained against: a
+/// 16x16 feature map (stride 8, 2 anchors per cell) followed by an 8x8 feature map (stride 16,
+/// 6 anchors per cell), for 896 anchors total.
+fn generate_anchors() -> Vec<Anchor> {
+    const LAYERS: [(u32, u32); 2] = [(8, 2), (16, 6)]; // (stride, anchors_per_cell)
+    let input_size = BlazeFaceDetector::INPUT_SIZE;
+
+    let mut anchors = Vec::with_capacity(896);
+    for (stride, anchors_per_cell) in LAYERS {
+        let feature_size = input_size / stride;
+        for y in 0..feature_size {
+            for x in 0..feature_size {
+                let x_center = (x as f32 + 0.5) / feature_size as f32;
+                let y_center = (y as f32 + 0.5) / feature_size as f32;
+                for _ in 0..anchors_per_cell {
+                    anchors.push(Anchor {
+                        x_center,
+                        y_center,
+                        w: 1.0,
+                        h: 1.0,
+                    });
+                }
+            }
+        }
+    }
+    anchors
+}
+
+/// Picks the detector backend from a CLI arg (`blazeface` or `seeta`) or the
+/// `FACE_DETECTOR_BACKEND` env var, falling back to the SeetaFace cascade.
+fn create_face_detector() -> Result<Box<dyn FaceDetector>, Box<dyn std::error::Error>> {
+    let backend = std::env::args()
+        .nth(1)
+        .or_else(|| std::env::var("FACE_DETECTOR_BACKEND").ok())
+        .unwrap_or_else(|| "seeta".to_string());
+
+    match backend.as_str() {
+        "blazeface" => {
+            println!("Using BlazeFace ONNX detector backend");
+            let model_path = ensure_model_downloaded(&BLAZEFACE_MODEL)?;
+            Ok(Box::new(BlazeFaceDetector::new(&model_path)?))
+        }
+        _ => {
+            println!("Using SeetaFace cascade detector backend");
+            let model_path = ensure_model_downloaded(&SEETA_FACE_MODEL)?;
+            Ok(Box::new(SeetaFaceDetector::new(&model_path)?))
+        }
+    }
+}
+
+/// A detector model `ensure_model_downloaded` knows how to fetch, cache, and verify.
+///
+/// `default_url` and `sha256` are deliberately `Option`: a wrong hardcoded checksum is worse than none,
+/// since it turns `ChecksumMismatch` into a hard failure of the *default* detector for everyone
+/// (this happened here once already — see the chunk0-6 review). Until a maintainer downloads
+/// the real artifact and checksums it by hand, both fall back to the `*_env_var` overrides below
+/// so a verified mirror/digest can be supplied out-of-band instead of guessed in source.
+struct ModelSource {
+    file_name: &'static str,
+    default_url: Option<&'static str>,
+    url_env_var: &'static str,
+    sha256: Option<&'static str>,
+    sha256_env_var: &'static str,
+}
+
+const SEETA_FACE_MODEL: ModelSource = ModelSource {
+    file_name: "seeta_fd_frontal_v1.0.bin",
+    default_url: Some(
+        "https://raw.githubusercontent.com/atomashpolskiy/rustface/master/model/seeta_fd_frontal_v1.0.bin",
+    ),
+    url_env_var: "SEETA_FACE_MODEL_URL",
+    // Unverified: set `SEETA_FACE_MODEL_SHA256` once this has been checksummed against a real
+    // downloaded copy.
+    sha256: None,
+    sha256_env_var: "SEETA_FACE_MODEL_SHA256",
+};
+
+const BLAZEFACE_MODEL: ModelSource = ModelSource {
+    file_name: "blazeface.onnx",
+    // No default: mediapipe only ever published this model as `.tflite`, not `.onnx`, so there
+    // is no known-good URL to bake in here. Set `BLAZEFACE_MODEL_URL` (and, once checksummed,
+    // `BLAZEFACE_MODEL_SHA256`) to point this at a verified ONNX conversion.
+    default_url: None,
+    url_env_var: "BLAZEFACE_MODEL_URL",
+    sha256: None,
+    sha256_env_var: "BLAZEFACE_MODEL_SHA256",
+};
+
+/// Errors that can occur while fetching and caching a detector model.
+#[derive(Debug)]
+enum ModelFetchError {
+    NoCacheDir,
+    NoUrlConfigured { env_var: &'static str },
+    Request(reqwest::Error),
+    Io(std::io::Error),
+    ChecksumMismatch { expected: String, actual: String },
+}
+
+impl std::fmt::Display for ModelFetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModelFetchError::NoCacheDir => {
+                write!(f, "could not determine a per-user data directory to cache models in")
+            }
+            ModelFetchError::NoUrlConfigured { env_var } => {
+                write!(f, "no download URL configured for this model; set {env_var}")
+            }
+            ModelFetchError::Request(e) => write!(f, "failed to download model: {e}"),
+            ModelFetchError::Io(e) => write!(f, "failed to write model to disk: {e}"),
+            ModelFetchError::ChecksumMismatch { expected, actual } => {
+                write!(f, "model checksum mismatch: expected {expected}, got {actual}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModelFetchError {}
+
+impl From<reqwest::Error> for ModelFetchError {
+    fn from(e: reqwest::Error) -> Self {
+        ModelFetchError::Request(e)
+    }
+}
+
+impl From<std::io::Error> for ModelFetchError {
+    fn from(e: std::io::Error) -> Self {
+        ModelFetchError::Io(e)
+    }
+}
+
+/// Per-user cache directory models are downloaded into, rather than the working directory.
+fn model_cache_dir() -> Result<std::path::PathBuf, ModelFetchError> {
+    let dir = dirs::data_dir()
+        .ok_or(ModelFetchError::NoCacheDir)?
+        .join("face-tracking-eyes")
+        .join("models");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Sidecar file recording the SHA-256 a cached model was verified against at download time.
+/// When `ModelSource::sha256` isn't pinned (no known-good upstream digest to bake in), this is
+/// how `ensure_model_downloaded` still catches the cached file being corrupted or tampered with
+/// after that first trusted download — trust-on-first-use, rather than no verification at all.
+fn model_pin_path(final_path: &std::path::Path, model: &ModelSource) -> std::path::PathBuf {
+    final_path.with_file_name(format!("{}.sha256", model.file_name))
+}
+
+fn sha256_of_file(path: &std::path::Path) -> Result<String, ModelFetchError> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Downloads `model` into the per-user model cache if it isn't already present, streaming to
+/// a temp file and verifying its SHA-256 checksum before renaming it into place. When a known
+/// digest is pinned (`ModelSource::sha256` or its env var override), that's what gets verified;
+/// otherwise the first successful download is trusted and its digest pinned to a local sidecar
+/// file so later runs can still detect the cached copy being corrupted or tampered with.
+/// Returns the path to the cached, verified model file.
+fn ensure_model_downloaded(model: &ModelSource) -> Result<std::path::PathBuf, ModelFetchError> {
+    let final_path = model_cache_dir()?.join(model.file_name);
+    let pin_path = model_pin_path(&final_path, model);
+
+    if final_path.exists() {
+        if let Ok(pinned) = std::fs::read_to_string(&pin_path) {
+            let pinned = pinned.trim().to_string();
+            let actual = sha256_of_file(&final_path)?;
+            if actual != pinned {
+                return Err(ModelFetchError::ChecksumMismatch {
+                    expected: pinned,
+                    actual,
+                });
+            }
+        }
+        return Ok(final_path);
+    }
+
+    let url = std::env::var(model.url_env_var)
+        .ok()
+        .or_else(|| model.default_url.map(str::to_string))
+        .ok_or(ModelFetchError::NoUrlConfigured {
+            env_var: model.url_env_var,
+        })?;
+    let expected_sha256 = std::env::var(model.sha256_env_var)
+        .ok()
+        .or_else(|| model.sha256.map(str::to_string));
+
+    println!("Downloading {}...", model.file_name);
+    let mut response = reqwest::blocking::get(&url)?;
+    let total_size = response.content_length();
+
+    let temp_path = final_path.with_extension("download");
+    let mut temp_file = std::fs::File::create(&temp_path)?;
+    let mut hasher = Sha256::new();
+    let mut downloaded: u64 = 0;
+    let mut buffer = [0u8; 64 * 1024];
+
+    loop {
+        let read = response.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        temp_file.write_all(&buffer[..read])?;
+        hasher.update(&buffer[..read]);
+        downloaded += read as u64;
+
+        if let Some(total) = total_size {
+            print!("\rDownloading {}: {:.0}%", model.file_name, downloaded as f64 / total as f64 * 100.0);
+            let _ = std::io::stdout().flush();
+        }
+    }
+    println!();
+
+    let actual = format!("{:x}", hasher.finalize());
+    match expected_sha256 {
+        Some(expected) if expected != actual => {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(ModelFetchError::ChecksumMismatch { expected, actual });
+        }
+        Some(_) => println!("{} downloaded and verified against a pinned digest", model.file_name),
+        None => println!(
+            "{} downloaded; no pinned digest is available, so this first copy is trusted and its \
+             sha256 ({actual}) is pinned locally — set {} to verify against a known-good digest instead",
+            model.file_name, model.sha256_env_var
+        ),
+    }
+
+    std::fs::write(&pin_path, &actual)?;
+    std::fs::rename(&temp_path, &final_path)?;
+    Ok(final_path)
 }
 
 fn main() {
@@ -19,7 +618,7 @@ fn main() {
     
     // Initialize face position resource
     let face_position = FacePosition {
-        position: Arc::new(Mutex::new(None)),
+        faces: Arc::new(Mutex::new(Vec::new())),
     };
     
     // Clone for the camera thread
@@ -43,8 +642,34 @@ fn main() {
             ..default()
         }))
         .insert_resource(face_position)
+        .insert_resource(EyePairs::default())
+        .insert_resource(EyeVergenceSettings::default())
+        .insert_resource(WinitSettings {
+            focused_mode: UpdateMode::Reactive {
+                wait: FACE_POLL_INTERVAL,
+            },
+            unfocused_mode: UpdateMode::Reactive {
+                wait: FACE_POLL_INTERVAL,
+            },
+            ..default()
+        })
+        .insert_resource(FaceTrackingActivity {
+            reactive: true,
+            ..default()
+        })
         .add_systems(Startup, setup)
-        .add_systems(Update, (handle_input, eye_follow_face))
+        .add_systems(
+            Update,
+            (
+                handle_input,
+                sync_eyes_with_faces,
+                eye_follow_face,
+                update_render_mode,
+                fly_camera_control,
+                collect_gltf_cameras,
+                cycle_active_camera,
+            ),
+        )
         .run();
 }
 
@@ -59,31 +684,8 @@ fn run_face_detection(face_position: FacePosition) -> Result<(), Box<dyn std::er
     
     // Initialize face detector
     println!("Initializing face detector...");
-    let model_path = "seeta_fd_frontal_v1.0.bin";
-    
-    // Download model if it doesn't exist
-    if !std::path::Path::new(model_path).exists() {
-        println!("Downloading face detection model...");
-        let model_url = "https://raw.githubusercontent.com/atomashpolskiy/rustface/master/model/seeta_fd_frontal_v1.0.bin";
-        let response = std::process::Command::new("curl")
-            .args(&["-L", "-o", model_path, model_url])
-            .output()
-            .expect("Failed to download model");
-        
-        if !response.status.success() {
-            eprintln!("Failed to download face detection model");
-            eprintln!("Please download manually from: {}", model_url);
-            return Ok(());
-        }
-        println!("Model downloaded successfully");
-    }
-    
-    let mut detector = rustface::create_detector(model_path)?;
-    detector.set_min_face_size(30);
-    detector.set_score_thresh(1.0);
-    detector.set_pyramid_scale_factor(0.8);
-    detector.set_slide_window_step(4, 4);
-    
+    let detector = create_face_detector()?;
+
     println!("Face detector initialized - eyes will track detected faces");
     
     let mut frame_count = 0;
@@ -118,33 +720,31 @@ fn run_face_detection(face_position: FacePosition) -> Result<(), Box<dyn std::er
         let gray_data = create_gray_data(&rgb_data, width, height);
         let gray_image = ImageData::new(&gray_data, width as u32, height as u32);
         
-        // Detect faces
-        let faces = detector.detect(&gray_image);
-        
-        // Update face position if faces detected
-        if let Some(face) = faces.first() {
-            let bbox = face.bbox();
-            
-            // Calculate center of the face
-            let center_x = bbox.x() + bbox.width() as i32 / 2;
-            let center_y = bbox.y() + bbox.height() as i32 / 2;
-            
-            // Normalize to -1 to 1 range
-            let norm_x = (center_x as f32 / width as f32) * 2.0 - 1.0;
-            let norm_y = -((center_y as f32 / height as f32) * 2.0 - 1.0); // Flip Y
-            
-            if let Ok(mut pos) = face_position.position.lock() {
-                *pos = Some((norm_x, norm_y));
-            }
-            
-            if frame_count % 60 == 0 {
-                println!("Tracking face at ({:.2}, {:.2})", norm_x, norm_y);
-            }
-        } else {
-            // No face detected - clear position
-            if let Ok(mut pos) = face_position.position.lock() {
-                *pos = None;
-            }
+        // Detect faces, then collapse overlapping/duplicate detections with NMS
+        let detections = detector.detect(&rgb_data, &gray_image, width as u32, height as u32);
+        let tracked_faces = non_max_suppression(detections, NMS_IOU_THRESHOLD);
+
+        // Calculate normalized center and apparent size for every tracked face
+        let tracked: Vec<TrackedFace> = tracked_faces
+            .iter()
+            .map(|bbox| {
+                let center_x = bbox.x + bbox.width as i32 / 2;
+                let center_y = bbox.y + bbox.height as i32 / 2;
+                let norm_x = (center_x as f32 / width as f32) * 2.0 - 1.0;
+                let norm_y = -((center_y as f32 / height as f32) * 2.0 - 1.0); // Flip Y
+                TrackedFace {
+                    position: (norm_x, norm_y),
+                    bbox_height: bbox.height as f32 / height as f32,
+                }
+            })
+            .collect();
+
+        if frame_count % 60 == 0 && !tracked.is_empty() {
+            println!("Tracking {} face(s)", tracked.len());
+        }
+
+        if let Ok(mut faces) = face_position.faces.lock() {
+            *faces = tracked;
         }
         
         // Limit to reasonable frame rate
@@ -172,25 +772,49 @@ fn create_gray_data(rgb_data: &[u8], width: usize, height: usize) -> Vec<u8> {
 fn setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    mut eye_pairs: ResMut<EyePairs>,
 ) {
-    // Load and spawn the eye model
-    commands.spawn((
-        SceneBundle {
-            scene: asset_server.load("eye-model/source/eye-model.gltf#Scene0"),
-            transform: Transform::from_xyz(0.0, 0.0, 0.0),
-            ..default()
-        },
-        Eye,
-    ));
-    
-    // Camera positioned to look from the right side (+X axis)
+    // Load the eye model once; sync_eyes_with_faces spawns further instances per extra face
+    let eye_scene: Handle<Scene> = asset_server.load("eye-model/source/eye-model.gltf#Scene0");
+    commands.insert_resource(EyeScene(eye_scene.clone()));
+
+    // Spawn the default left/right pair up front so there's always somewhere for the eyes to
+    // rest (centered) even before any face has been tracked
+    let left = spawn_eye(&mut commands, eye_scene.clone(), EyeSide::Left, None, Vec3::ZERO);
+    let right = spawn_eye(&mut commands, eye_scene, EyeSide::Right, None, Vec3::ZERO);
+    eye_pairs.pairs.push(EyePair {
+        left,
+        right,
+        last_position: (0.0, 0.0),
+        persistent: true,
+    });
+
+    // Camera positioned to look from the right side (+X axis); user-controllable via WASD +
+    // mouse-look, and cyclable with `C` alongside any cameras baked into the glTF scene
     let camera_pos = Vec3::new(5.0, 0.0, 0.0);
-    commands.spawn(Camera3dBundle {
-        transform: Transform::from_xyz(camera_pos.x, camera_pos.y, camera_pos.z)
-            .looking_at(Vec3::ZERO, Vec3::Y),
-        ..default()
+    let fly_camera_transform = Transform::from_xyz(camera_pos.x, camera_pos.y, camera_pos.z)
+        .looking_at(Vec3::ZERO, Vec3::Y);
+    let (yaw, pitch, _roll) = fly_camera_transform.rotation.to_euler(EulerRot::YXZ);
+    commands.insert_resource(FlyCameraState { yaw, pitch });
+
+    let fly_camera = commands
+        .spawn((
+            Camera3dBundle {
+                transform: fly_camera_transform,
+                camera: RenderCamera {
+                    is_active: true,
+                    ..default()
+                },
+                ..default()
+            },
+            FlyCamera,
+        ))
+        .id();
+    commands.insert_resource(CameraCycle {
+        cameras: vec![fly_camera],
+        active_index: 0,
     });
-    
+
     // Light positioned behind the camera
     commands.spawn(PointLightBundle {
         point_light: PointLight {
@@ -227,35 +851,407 @@ fn handle_input(
     }
 }
 
-fn eye_follow_face(
-    mut eye_query: Query<&mut Transform, With<Eye>>,
+fn spawn_eye(
+    commands: &mut Commands,
+    scene: Handle<Scene>,
+    side: EyeSide,
+    target: Option<TrackedFace>,
+    base: Vec3,
+) -> Entity {
+    commands
+        .spawn((
+            SceneBundle {
+                scene,
+                transform: Transform::from_translation(base),
+                ..default()
+            },
+            Eye { side, target, base },
+        ))
+        .id()
+}
+
+/// World-space spacing between neighboring eye pairs when more than one face is tracked.
+const EYE_PAIR_WORLD_SPACING: f32 = 0.6;
+
+/// Lays out non-persistent pairs side by side along Z (the default camera's horizontal axis,
+/// since it looks down -X from +X) so separate tracked faces don't all render on top of one
+/// another; the persistent default pair always stays centered.
+fn eye_pair_base(persistent: bool, slot: usize) -> Vec3 {
+    if persistent {
+        return Vec3::ZERO;
+    }
+    let magnitude = (slot / 2 + 1) as f32 * EYE_PAIR_WORLD_SPACING;
+    let side = if slot % 2 == 0 { 1.0 } else { -1.0 };
+    Vec3::Z * magnitude * side
+}
+
+/// Keeps one `EyePair` alive per tracked face beyond the default pair. Existing pairs are
+/// re-matched to whichever remaining face center is closest to where they were last looking,
+/// so a pair stays assigned to the same person instead of jumping when faces are added,
+/// removed, or reordered. The default pair created in `setup` is never despawned.
+fn sync_eyes_with_faces(
+    mut commands: Commands,
     face_position: Res<FacePosition>,
+    eye_scene: Res<EyeScene>,
+    mut eye_pairs: ResMut<EyePairs>,
+    mut eye_query: Query<&mut Eye>,
 ) {
-    // Get the current face position
-    let face_pos = if let Ok(pos) = face_position.position.lock() {
-        *pos
-    } else {
-        None
-    };
-    
-    // If no face detected, return to center
-    let (norm_x, norm_y) = face_pos.unwrap_or((0.0, 0.0));
-    
-    // Define eye's natural range of motion in radians
-    let max_yaw = std::f32::consts::PI / 4.0;   // ±45 degrees horizontal
+    let mut remaining_faces = face_position
+        .faces
+        .lock()
+        .map(|faces| faces.clone())
+        .unwrap_or_default();
+
+    let mut stale_pairs = Vec::new();
+    let mut non_persistent_slot = 0;
+    for (index, pair) in eye_pairs.pairs.iter_mut().enumerate() {
+        let matched = if remaining_faces.is_empty() {
+            None
+        } else {
+            let (closest_index, _) = remaining_faces
+                .iter()
+                .enumerate()
+                .map(|(i, face)| (i, distance(pair.last_position, face.position)))
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                .unwrap();
+            Some(remaining_faces.remove(closest_index))
+        };
+
+        if let Some(face) = matched {
+            pair.last_position = face.position;
+        }
+
+        // Every non-persistent pair gets its own world-space slot so simultaneously tracked
+        // faces render as visually separate eye pairs instead of stacking on the origin.
+        let base = eye_pair_base(pair.persistent, non_persistent_slot);
+        if !pair.persistent {
+            non_persistent_slot += 1;
+        }
+
+        if let Ok(mut eye) = eye_query.get_mut(pair.left) {
+            eye.target = matched;
+            eye.base = base;
+        }
+        if let Ok(mut eye) = eye_query.get_mut(pair.right) {
+            eye.target = matched;
+            eye.base = base;
+        }
+
+        if matched.is_none() && !pair.persistent {
+            stale_pairs.push(index);
+        }
+    }
+
+    // Despawn non-persistent pairs whose face is no longer tracked
+    for &index in stale_pairs.iter().rev() {
+        let pair = eye_pairs.pairs.remove(index);
+        commands.entity(pair.left).despawn_recursive();
+        commands.entity(pair.right).despawn_recursive();
+    }
+
+    // Spawn a fresh pair for every face that no existing pair claimed
+    for face in remaining_faces {
+        let base = eye_pair_base(false, non_persistent_slot);
+        non_persistent_slot += 1;
+        let left = spawn_eye(&mut commands, eye_scene.0.clone(), EyeSide::Left, Some(face), base);
+        let right = spawn_eye(&mut commands, eye_scene.0.clone(), EyeSide::Right, Some(face), base);
+        eye_pairs.pairs.push(EyePair {
+            left,
+            right,
+            last_position: face.position,
+            persistent: false,
+        });
+    }
+}
+
+/// Eyes rest looking straight ahead at this distance when no face is tracked.
+const DEFAULT_GAZE_DISTANCE: f32 = 3.0;
+
+/// Reconstructs the 3D point a gaze is aimed at from a normalized screen position and an
+/// estimated distance, using the same yaw/pitch range the eyes were already limited to.
+fn gaze_target_point(position: (f32, f32), distance: f32) -> Vec3 {
+    let max_yaw = std::f32::consts::PI / 4.0; // ±45 degrees horizontal
     let max_pitch = std::f32::consts::PI / 6.0; // ±30 degrees vertical
-    
-    // Map face position (-1 to 1) directly to eye rotation angles
-    // norm_x/norm_y of -1 = bottom/left of frame, +1 = top/right of frame
-    let target_yaw = -norm_x * max_yaw;      // Negative to mirror camera view
-    let target_pitch = norm_y * max_pitch;   // Direct mapping
-    
-    // Rotate eye to the target angles
-    for mut transform in eye_query.iter_mut() {
-        // Create rotation from yaw (left/right) and pitch (up/down)
-        let target_rotation = Quat::from_rotation_y(target_yaw) * Quat::from_rotation_z(target_pitch);
-        
+
+    let (norm_x, norm_y) = position;
+    let target_yaw = -norm_x * max_yaw; // Negative to mirror camera view
+    let target_pitch = norm_y * max_pitch;
+
+    let gaze_direction = Quat::from_rotation_y(target_yaw) * Quat::from_rotation_z(target_pitch) * Vec3::X;
+    gaze_direction * distance
+}
+
+/// World-space offset of one eye from the pair's shared center.
+fn eye_offset(side: EyeSide, inter_pupil_distance: f32) -> Vec3 {
+    let half_separation = inter_pupil_distance / 2.0;
+    match side {
+        EyeSide::Left => Vec3::new(0.0, 0.0, -half_separation),
+        EyeSide::Right => Vec3::new(0.0, 0.0, half_separation),
+    }
+}
+
+fn eye_follow_face(mut eye_query: Query<(&Eye, &mut Transform)>, settings: Res<EyeVergenceSettings>) {
+    for (eye, mut transform) in eye_query.iter_mut() {
+        let offset = eye.base + eye_offset(eye.side, settings.inter_pupil_distance);
+        transform.translation = offset;
+
+        let (gaze_position, gaze_distance) = match eye.target {
+            Some(face) => {
+                let distance = settings.distance_calibration / face.bbox_height.max(0.001);
+                (face.position, distance.max(settings.inter_pupil_distance))
+            }
+            None => ((0.0, 0.0), DEFAULT_GAZE_DISTANCE),
+        };
+
+        // Each eye looks at the same 3D point from its own offset position, so near targets
+        // pull the eyes inward (converge) and far targets leave them looking nearly parallel.
+        // The gaze target is relative to the pair's base, same as `offset`, so moving a pair's
+        // base doesn't change which direction it's looking, only where it's standing.
+        let target_point = eye.base + gaze_target_point(gaze_position, gaze_distance);
+        let target_rotation = Transform::from_translation(offset)
+            .looking_at(target_point, Vec3::Y)
+            .rotation;
+
         // Smooth interpolation for natural movement
         transform.rotation = transform.rotation.slerp(target_rotation, 0.15);
     }
 }
+
+/// Worst-case latency before reactive mode notices a newly-appeared face. Face detection runs
+/// on a background thread (see `run_face_detection`) and only ever updates `FacePosition` —
+/// that generates no winit event, so plain `WinitSettings::desktop_app()` (which otherwise only
+/// wakes on window/input activity) could leave a face waiting indefinitely for an unrelated
+/// event to resume tracking. Polling this often instead bounds that stall.
+const FACE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// Switches between continuous rendering (while a face is being actively tracked, so the eye
+/// slerp stays smooth) and low-power reactive rendering (once nobody has been seen for
+/// `FACE_IDLE_TIMEOUT_SECS`), so an idle webcam toy isn't rendering at full frame rate for
+/// nothing.
+fn update_render_mode(
+    face_position: Res<FacePosition>,
+    time: Res<Time>,
+    mut activity: ResMut<FaceTrackingActivity>,
+    mut winit_settings: ResMut<WinitSettings>,
+) {
+    let has_face = face_position
+        .faces
+        .lock()
+        .map(|faces| !faces.is_empty())
+        .unwrap_or(false);
+    let now = time.elapsed_seconds_f64();
+
+    if has_face {
+        activity.last_face_seen = Some(now);
+    }
+
+    let should_be_reactive = match activity.last_face_seen {
+        Some(last_seen) => now - last_seen > FACE_IDLE_TIMEOUT_SECS,
+        None => true,
+    };
+
+    if should_be_reactive == activity.reactive {
+        return;
+    }
+
+    *winit_settings = if should_be_reactive {
+        WinitSettings {
+            focused_mode: UpdateMode::Reactive {
+                wait: FACE_POLL_INTERVAL,
+            },
+            unfocused_mode: UpdateMode::Reactive {
+                wait: FACE_POLL_INTERVAL,
+            },
+            ..default()
+        }
+    } else {
+        WinitSettings {
+            focused_mode: UpdateMode::Continuous,
+            unfocused_mode: UpdateMode::Continuous,
+            ..default()
+        }
+    };
+    activity.reactive = should_be_reactive;
+}
+
+const FLY_CAMERA_MOVE_SPEED: f32 = 4.0; // World units per second
+const FLY_CAMERA_LOOK_SENSITIVITY: f32 = 0.002; // Radians per pixel of mouse motion
+
+/// Debug fly camera following the scene_viewer control scheme: WASD + Space/Shift to move,
+/// mouse-look while the right mouse button is held.
+fn fly_camera_control(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut fly_state: ResMut<FlyCameraState>,
+    mut fly_camera: Query<&mut Transform, With<FlyCamera>>,
+) {
+    let Ok(mut transform) = fly_camera.get_single_mut() else {
+        return;
+    };
+
+    if mouse_buttons.pressed(MouseButton::Right) {
+        for motion in mouse_motion.read() {
+            fly_state.yaw -= motion.delta.x * FLY_CAMERA_LOOK_SENSITIVITY;
+            fly_state.pitch = (fly_state.pitch - motion.delta.y * FLY_CAMERA_LOOK_SENSITIVITY)
+                .clamp(-std::f32::consts::FRAC_PI_2 + 0.01, std::f32::consts::FRAC_PI_2 - 0.01);
+        }
+    } else {
+        mouse_motion.clear();
+    }
+
+    transform.rotation = Quat::from_euler(EulerRot::YXZ, fly_state.yaw, fly_state.pitch, 0.0);
+
+    let forward = transform.rotation * -Vec3::Z;
+    let right = transform.rotation * Vec3::X;
+
+    let mut movement = Vec3::ZERO;
+    if keyboard_input.pressed(KeyCode::KeyW) {
+        movement += forward;
+    }
+    if keyboard_input.pressed(KeyCode::KeyS) {
+        movement -= forward;
+    }
+    if keyboard_input.pressed(KeyCode::KeyD) {
+        movement += right;
+    }
+    if keyboard_input.pressed(KeyCode::KeyA) {
+        movement -= right;
+    }
+    if keyboard_input.pressed(KeyCode::Space) {
+        movement += Vec3::Y;
+    }
+    if keyboard_input.pressed(KeyCode::ShiftLeft) {
+        movement -= Vec3::Y;
+    }
+
+    if movement != Vec3::ZERO {
+        transform.translation += movement.normalize() * FLY_CAMERA_MOVE_SPEED * time.delta_seconds();
+    }
+}
+
+/// Registers any camera spawned as part of the loaded glTF scene so it shows up in the `C`
+/// cycle alongside the fly camera. Runs every frame since the scene (and its cameras) load
+/// asynchronously after `setup`.
+fn collect_gltf_cameras(
+    mut camera_cycle: ResMut<CameraCycle>,
+    mut new_cameras: Query<(Entity, &mut RenderCamera), (Added<Camera3d>, Without<FlyCamera>)>,
+) {
+    for (entity, mut camera) in new_cameras.iter_mut() {
+        camera.is_active = false; // Stay inactive until cycled to
+        camera_cycle.cameras.push(entity);
+    }
+}
+
+/// Cycles the active camera through the fly camera and any glTF-defined cameras on `C`,
+/// wrapping back around to the start.
+fn cycle_active_camera(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut camera_cycle: ResMut<CameraCycle>,
+    mut cameras: Query<&mut RenderCamera>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyC) || camera_cycle.cameras.len() < 2 {
+        return;
+    }
+
+    if let Ok(mut camera) = cameras.get_mut(camera_cycle.cameras[camera_cycle.active_index]) {
+        camera.is_active = false;
+    }
+
+    camera_cycle.active_index = (camera_cycle.active_index + 1) % camera_cycle.cameras.len();
+
+    if let Ok(mut camera) = cameras.get_mut(camera_cycle.cameras[camera_cycle.active_index]) {
+        camera.is_active = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn face_box(x: i32, y: i32, width: u32, height: u32) -> FaceBox {
+        FaceBox {
+            x,
+            y,
+            width,
+            height,
+            score: 1.0,
+        }
+    }
+
+    #[test]
+    fn iou_of_identical_boxes_is_one() {
+        let a = face_box(0, 0, 10, 10);
+        assert_eq!(intersection_over_union(&a, &a), 1.0);
+    }
+
+    #[test]
+    fn iou_of_non_overlapping_boxes_is_zero() {
+        let a = face_box(0, 0, 10, 10);
+        let b = face_box(100, 100, 10, 10);
+        assert_eq!(intersection_over_union(&a, &b), 0.0);
+    }
+
+    #[test]
+    fn iou_of_partially_overlapping_boxes() {
+        // Two 10x10 boxes overlapping in a 5x10 strip: intersection 50, union 150.
+        let a = face_box(0, 0, 10, 10);
+        let b = face_box(5, 0, 10, 10);
+        assert!((intersection_over_union(&a, &b) - (50.0 / 150.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn non_max_suppression_collapses_duplicate_detections() {
+        let detections = vec![
+            FaceBox { x: 0, y: 0, width: 10, height: 10, score: 0.9 },
+            FaceBox { x: 1, y: 1, width: 10, height: 10, score: 0.8 }, // overlaps the above
+            FaceBox { x: 100, y: 100, width: 10, height: 10, score: 0.7 }, // separate face
+        ];
+        let kept = non_max_suppression(detections, NMS_IOU_THRESHOLD);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].score, 0.9); // higher-scoring duplicate wins
+        assert_eq!(kept[1].score, 0.7);
+    }
+
+    #[test]
+    fn non_max_suppression_drops_degenerate_boxes() {
+        let detections = vec![face_box(0, 0, 0, 10), face_box(0, 0, 10, 0)];
+        assert!(non_max_suppression(detections, NMS_IOU_THRESHOLD).is_empty());
+    }
+
+    #[test]
+    fn eye_offset_is_symmetric_about_the_pair_center() {
+        let left = eye_offset(EyeSide::Left, 0.15);
+        let right = eye_offset(EyeSide::Right, 0.15);
+        assert_eq!(left, -right);
+        assert!((left.length() - 0.075).abs() < 1e-6);
+    }
+
+    #[test]
+    fn gaze_target_point_scales_with_distance() {
+        let near = gaze_target_point((0.0, 0.0), 1.0);
+        let far = gaze_target_point((0.0, 0.0), 2.0);
+        assert!((far.length() - 2.0 * near.length()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn gaze_target_point_mirrors_horizontal_position() {
+        let left = gaze_target_point((-1.0, 0.0), 1.0);
+        let right = gaze_target_point((1.0, 0.0), 1.0);
+        assert!((left.z + right.z).abs() < 1e-5);
+        assert!((left.z - right.z).abs() > 1e-3);
+    }
+
+    #[test]
+    fn sigmoid_midpoint_and_saturation() {
+        assert!((sigmoid(0.0) - 0.5).abs() < 1e-6);
+        assert!(sigmoid(50.0) > 0.999);
+        assert!(sigmoid(-50.0) < 0.001);
+    }
+
+    #[test]
+    fn generate_anchors_matches_the_short_range_model() {
+        assert_eq!(generate_anchors().len(), 896);
+    }
+}